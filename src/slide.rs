@@ -1,5 +1,7 @@
 //! This module defines a  `Slide` iterator over `Vector`s and slices
 
+use std::collections::VecDeque;
+
 /// Immutable iterator that returns both an element, and slice
 /// representing the remaining elements
 ///
@@ -21,32 +23,165 @@
 pub struct Slide<'a, T: 'a> {
     v: &'a [T],
     pos: usize,
+    end: usize,
 }
 
 impl<'a, T: Sized> Iterator for Slide<'a, T> {
     type Item = (&'a T, Option<&'a [T]>);
-    
+
     #[inline]
     fn next(&mut self) -> Option<(&'a T, Option<&'a [T]>)> {
+        if self.pos >= self.end {
+            return None;
+        }
+
+        let val = &self.v[self.pos];
+        self.pos += 1;
+
+        if self.v.len() > self.pos {
+            Some((val, Some(&self.v[self.pos..])))
+        } else {
+            Some((val, None))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let diff = self.end - self.pos;
+
+        (diff, Some(diff))
+    }
+}
+
+/// `Slide` can be driven from either end: `next_back` yields the last
+/// not-yet-consumed element paired with the slice of elements that
+/// still follow it in the original ordering (`None` when that element
+/// is the final one in the underlying slice). Forward and backward
+/// iteration share the `pos`/`end` cursors, so they meet in the
+/// middle without yielding an element twice.
+impl<'a, T: Sized> DoubleEndedIterator for Slide<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a T, Option<&'a [T]>)> {
+        if self.pos >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let val = &self.v[self.end];
+
+        if self.v.len() > self.end + 1 {
+            Some((val, Some(&self.v[self.end + 1..])))
+        } else {
+            Some((val, None))
+        }
+    }
+}
+
+/// Immutable iterator that returns contiguous, fixed-size windows of
+/// a slice
+///
+/// Each call to `next` advances the start position by one, so a
+/// 5-element vec sliced with `n == 3` yields `[1, 2, 3]`, `[2, 3, 4]`,
+/// then `[3, 4, 5]`. Once fewer than `n` elements remain, `next`
+/// returns `None`. Passing `n == 0` always yields `None`.
+/// # Example
+/// ```
+/// use argparse::slide::{SlideN, Slider};
+///
+/// let v = vec![1, 2, 3, 4, 5];
+///
+/// for window in v.slide_n(3) {
+///     println!("{:?}", window)
+/// }
+/// ```
+pub struct SlideN<'a, T: 'a> {
+    v: &'a [T],
+    n: usize,
+    pos: usize,
+}
+
+impl<'a, T: Sized> Iterator for SlideN<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.n == 0 || self.pos + self.n > self.v.len() {
+            return None;
+        }
+
+        let window = &self.v[self.pos..self.pos + self.n];
+        self.pos += 1;
+
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+
+        let remaining = (self.v.len() - self.pos).saturating_sub(self.n - 1);
+
+        (remaining, Some(remaining))
+    }
+}
+
+/// Immutable iterator that returns an element together with the
+/// slices of elements before and after it
+///
+/// This generalizes `Slide`'s head/tail split to a lookbehind and
+/// lookahead: the first slice holds everything already passed, the
+/// middle value is the current element, and the last slice mirrors
+/// `Slide`'s tail, returning `None` instead of an empty slice once
+/// there are no elements left.
+/// # Example
+/// ```
+/// use argparse::slide::{Context, Slider};
+///
+/// let v = vec![1, 2, 3, 4, 5];
+///
+/// for (before, x, opt_after) in v.context() {
+///     println!("{:?} {} {:?}", before, x, opt_after);
+/// }
+/// ```
+pub struct Context<'a, T: 'a> {
+    v: &'a [T],
+    pos: usize,
+}
+
+impl<'a, T: Sized> Iterator for Context<'a, T> {
+    type Item = (&'a [T], &'a T, Option<&'a [T]>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a [T], &'a T, Option<&'a [T]>)> {
         self.v.get(self.pos).map(|val| {
-            self.pos = self.pos + 1;
-            
+            let before = &self.v[..self.pos];
+            self.pos += 1;
+
             if self.v.len() > self.pos {
-                (val, Some(&self.v[self.pos..]))
+                (before, val, Some(&self.v[self.pos..]))
             } else {
-                (val, None)
+                (before, val, None)
             }
         })
     }
-    
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let diff = self.v.len() - self.pos;
-        
+
         (diff, Some(diff))
     }
 }
 
+impl<'a, T: Sized> ExactSizeIterator for Context<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.v.len() - self.pos
+    }
+}
+
 /// Interface for all types that can produce a `Slide` iterator
 pub trait Slider<'a, T: Sized> {
     /// Calling this method shall produce a `Slide` iterator
@@ -63,23 +198,216 @@ pub trait Slider<'a, T: Sized> {
     /// }
     /// ```
     fn slide(&'a self) -> Slide<'a, T>;
+
+    /// Calling this method shall produce a `SlideN` iterator over
+    /// windows of length `n`
+    /// # Example
+    /// ```
+    /// use argparse::slide::{SlideN, Slider};
+    ///
+    /// let v = vec![1, 2, 3, 4, 5];
+    ///
+    /// for window in v.slide_n(3) {
+    ///     println!("{:?}", window)
+    /// }
+    /// ```
+    fn slide_n(&'a self, n: usize) -> SlideN<'a, T>;
+
+    /// Calling this method shall produce a `Context` iterator
+    /// # Example
+    /// ```
+    /// use argparse::slide::{Context, Slider};
+    ///
+    /// let v = vec![1, 2, 3, 4, 5];
+    ///
+    /// for (before, x, opt_after) in v.context() {
+    ///     println!("{:?} {} {:?}", before, x, opt_after);
+    /// }
+    /// ```
+    fn context(&'a self) -> Context<'a, T>;
 }
 
 impl<'a, T> Slider<'a, T> for &'a [T] {
     fn slide(&'a self)  -> Slide<'a, T> {
-        Slide { v: self, pos: 0}
+        Slide { v: self, pos: 0, end: self.len() }
+    }
+
+    fn slide_n(&'a self, n: usize) -> SlideN<'a, T> {
+        SlideN { v: self, n, pos: 0 }
+    }
+
+    fn context(&'a self) -> Context<'a, T> {
+        Context { v: self, pos: 0 }
     }
 }
 
 impl<'a, T> Slider<'a, T> for Vec<T> {
     fn slide(&'a self)  -> Slide<'a, T> {
-        Slide { v: &self[..], pos: 0}
+        Slide { v: &self[..], pos: 0, end: self.len() }
+    }
+
+    fn slide_n(&'a self, n: usize) -> SlideN<'a, T> {
+        SlideN { v: &self[..], n, pos: 0 }
+    }
+
+    fn context(&'a self) -> Context<'a, T> {
+        Context { v: &self[..], pos: 0 }
+    }
+}
+
+/// Owning iterator that returns both an element and a bounded
+/// look-ahead window of the up-to-`n` elements that follow it,
+/// pulling from an arbitrary source iterator instead of a
+/// pre-collected slice
+///
+/// Unlike `Slide`, `SlideIter` works over any `IntoIterator` whose
+/// items are `Clone`, so callers can stream values straight out of
+/// something like `std::env::args()` without collecting them into a
+/// `Vec` first. Internally it keeps a `VecDeque` buffer capped at `n`
+/// elements: each call to `next` tops the buffer back up to `n` by
+/// pulling exactly as many elements from the source as were consumed,
+/// so memory use stays bounded regardless of how long (or unbounded)
+/// the source is. The window shrinks and yields `None` once the
+/// source runs dry, exactly as `Slide`'s tail does. With `n == 0` the
+/// iterator yields nothing, as `SlideN` does.
+pub struct SlideIter<I: Iterator> {
+    source: I,
+    buf: VecDeque<I::Item>,
+    n: usize,
+}
+
+impl<I: Iterator> Iterator for SlideIter<I>
+where
+    I::Item: Clone,
+{
+    type Item = (I::Item, Option<Vec<I::Item>>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(I::Item, Option<Vec<I::Item>>)> {
+        if self.n == 0 {
+            return None;
+        }
+
+        let current = match self.buf.pop_front() {
+            Some(val) => val,
+            None => self.source.next()?,
+        };
+
+        while self.buf.len() < self.n {
+            match self.source.next() {
+                Some(val) => self.buf.push_back(val),
+                None => break,
+            }
+        }
+
+        if self.buf.is_empty() {
+            Some((current, None))
+        } else {
+            Some((current, Some(self.buf.iter().cloned().collect())))
+        }
+    }
+}
+
+/// Interface for all types that can produce a `SlideIter` iterator
+pub trait IterSlider: IntoIterator + Sized
+where
+    Self::Item: Clone,
+{
+    /// Calling this method shall produce a `SlideIter` iterator whose
+    /// look-ahead window holds up to `n` elements
+    /// # Example
+    /// ```
+    /// use argparse::slide::IterSlider;
+    ///
+    /// for (x, opt_window) in std::env::args().slide_iter(2) {
+    ///     if let Some(window) = opt_window {
+    ///         println!("{} followed by {:?}", x, window)
+    ///     }
+    /// }
+    /// ```
+    fn slide_iter(self, n: usize) -> SlideIter<Self::IntoIter>;
+}
+
+impl<II: IntoIterator> IterSlider for II
+where
+    II::Item: Clone,
+{
+    fn slide_iter(self, n: usize) -> SlideIter<Self::IntoIter> {
+        SlideIter {
+            source: self.into_iter(),
+            buf: VecDeque::new(),
+            n,
+        }
+    }
+}
+
+/// Owning iterator that consumes a `Vec` and returns both an element
+/// and an owned `Vec` of the elements that still follow it
+///
+/// `Slide` always borrows from the underlying slice, which blocks
+/// building owned collections out of its windows. `IntoSlide` takes
+/// the `Vec` by value instead, so each item can be fed straight into
+/// `FromIterator`/`collect` without the caller cloning borrowed
+/// slices afterward.
+pub struct IntoSlide<T> {
+    v: Vec<T>,
+    pos: usize,
+}
+
+impl<T: Clone> Iterator for IntoSlide<T> {
+    type Item = (T, Option<Vec<T>>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(T, Option<Vec<T>>)> {
+        if self.pos >= self.v.len() {
+            return None;
+        }
+
+        let val = self.v[self.pos].clone();
+        self.pos += 1;
+
+        if self.v.len() > self.pos {
+            Some((val, Some(self.v[self.pos..].to_vec())))
+        } else {
+            Some((val, None))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let diff = self.v.len() - self.pos;
+
+        (diff, Some(diff))
+    }
+}
+
+/// Interface for types that can produce an owning `IntoSlide` iterator
+pub trait IntoSlider<T: Clone> {
+    /// Calling this method shall consume `self` and produce an
+    /// `IntoSlide` iterator
+    /// # Example
+    /// ```
+    /// use argparse::slide::IntoSlider;
+    ///
+    /// let v = vec![1, 2, 3, 4, 5];
+    ///
+    /// let tail: Vec<i32> = v.into_slide()
+    ///     .filter_map(|(_, opt_rest)| opt_rest)
+    ///     .next()
+    ///     .unwrap();
+    /// ```
+    fn into_slide(self) -> IntoSlide<T>;
+}
+
+impl<T: Clone> IntoSlider<T> for Vec<T> {
+    fn into_slide(self) -> IntoSlide<T> {
+        IntoSlide { v: self, pos: 0 }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Slider};
+    use super::{IntoSlider, IterSlider, Slider};
     
     #[test]
     fn test_zero() {
@@ -125,4 +453,215 @@ mod test {
         assert_eq!(it.next(), Some((&10, None)));
         assert_eq!(it.next(), None);
     }
+
+    #[test]
+    fn test_slide_n_zero() {
+        let v = vec![1, 2, 3];
+        let mut it = v.slide_n(0);
+
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_slide_n_larger_than_len() {
+        let v = vec![1, 2, 3];
+        let mut it = v.slide_n(4);
+
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_slide_n_exact_len() {
+        let v = vec![1, 2, 3];
+        let mut it = v.slide_n(3);
+
+        assert_eq!(it.next(), Some(&[1, 2, 3][..]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_slide_n_three() {
+        let v = vec![1, 2, 3, 4, 5];
+        let mut it = v.slide_n(3);
+
+        assert_eq!(it.next(), Some(&[1, 2, 3][..]));
+        assert_eq!(it.next(), Some(&[2, 3, 4][..]));
+        assert_eq!(it.next(), Some(&[3, 4, 5][..]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_next_back_empty() {
+        let v: Vec<u8> = vec![];
+        let mut it = v.slide();
+
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_one() {
+        let v = vec![1];
+        let mut it = v.slide();
+
+        assert_eq!(it.next_back(), Some((&1, None)));
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_ten() {
+        let v = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut it = v.slide();
+
+        assert_eq!(it.next_back(), Some((&10, None)));
+        assert_eq!(it.next_back(), Some((&9, Some(&[10][..]))));
+        assert_eq!(it.next_back(), Some((&8, Some(&[9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&7, Some(&[8, 9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&6, Some(&[7, 8, 9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&5, Some(&[6, 7, 8, 9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&4, Some(&[5, 6, 7, 8, 9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&3, Some(&[4, 5, 6, 7, 8, 9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&2, Some(&[3, 4, 5, 6, 7, 8, 9, 10][..]))));
+        assert_eq!(it.next_back(), Some((&1, Some(&[2, 3, 4, 5, 6, 7, 8, 9, 10][..]))));
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_meet_in_middle() {
+        let v = vec![1, 2, 3, 4, 5];
+        let mut it = v.slide();
+
+        assert_eq!(it.next(), Some((&1, Some(&[2, 3, 4, 5][..]))));
+        assert_eq!(it.next_back(), Some((&5, None)));
+        assert_eq!(it.next(), Some((&2, Some(&[3, 4, 5][..]))));
+        assert_eq!(it.next_back(), Some((&4, Some(&[5][..]))));
+        assert_eq!(it.next(), Some((&3, Some(&[4, 5][..]))));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_zero() {
+        let v: Vec<u8> = vec![];
+        let mut it = v.into_iter().slide_iter(2);
+
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_iter_window_zero() {
+        let v = vec![1, 2, 3];
+        let mut it = v.into_iter().slide_iter(0);
+
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_iter_one() {
+        let v = vec![1];
+        let mut it = v.into_iter().slide_iter(2);
+
+        assert_eq!(it.next(), Some((1, None)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_iter_ten_full_window() {
+        let v = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut it = v.into_iter().slide_iter(9);
+
+        assert_eq!(it.next(), Some((1, Some(vec![2, 3, 4, 5, 6, 7, 8, 9, 10]))));
+        assert_eq!(it.next(), Some((2, Some(vec![3, 4, 5, 6, 7, 8, 9, 10]))));
+        assert_eq!(it.next(), Some((3, Some(vec![4, 5, 6, 7, 8, 9, 10]))));
+        assert_eq!(it.next(), Some((4, Some(vec![5, 6, 7, 8, 9, 10]))));
+        assert_eq!(it.next(), Some((5, Some(vec![6, 7, 8, 9, 10]))));
+        assert_eq!(it.next(), Some((6, Some(vec![7, 8, 9, 10]))));
+        assert_eq!(it.next(), Some((7, Some(vec![8, 9, 10]))));
+        assert_eq!(it.next(), Some((8, Some(vec![9, 10]))));
+        assert_eq!(it.next(), Some((9, Some(vec![10]))));
+        assert_eq!(it.next(), Some((10, None)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_iter_bounded_window() {
+        let v = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut it = v.into_iter().slide_iter(3);
+
+        assert_eq!(it.next(), Some((1, Some(vec![2, 3, 4]))));
+        assert_eq!(it.next(), Some((2, Some(vec![3, 4, 5]))));
+        assert_eq!(it.next(), Some((3, Some(vec![4, 5, 6]))));
+        assert_eq!(it.next(), Some((4, Some(vec![5, 6, 7]))));
+        assert_eq!(it.next(), Some((5, Some(vec![6, 7, 8]))));
+        assert_eq!(it.next(), Some((6, Some(vec![7, 8, 9]))));
+        assert_eq!(it.next(), Some((7, Some(vec![8, 9, 10]))));
+        assert_eq!(it.next(), Some((8, Some(vec![9, 10]))));
+        assert_eq!(it.next(), Some((9, Some(vec![10]))));
+        assert_eq!(it.next(), Some((10, None)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_context_zero() {
+        let v: Vec<u8> = vec![];
+        let mut it = v.context();
+
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_context_one() {
+        let v = vec![1];
+        let mut it = v.context();
+
+        assert_eq!(it.len(), 1);
+        assert_eq!(it.next(), Some((&[][..], &1, None)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_context_five() {
+        let v = vec![1, 2, 3, 4, 5];
+        let mut it = v.context();
+
+        assert_eq!(it.len(), 5);
+        assert_eq!(it.next(), Some((&[][..], &1, Some(&[2, 3, 4, 5][..]))));
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some((&[1][..], &2, Some(&[3, 4, 5][..]))));
+        assert_eq!(it.next(), Some((&[1, 2][..], &3, Some(&[4, 5][..]))));
+        assert_eq!(it.next(), Some((&[1, 2, 3][..], &4, Some(&[5][..]))));
+        assert_eq!(it.next(), Some((&[1, 2, 3, 4][..], &5, None)));
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_into_slide_zero() {
+        let v: Vec<u8> = vec![];
+        let mut it = v.into_slide();
+
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_into_slide_one() {
+        let v = vec![1];
+        let mut it = v.into_slide();
+
+        assert_eq!(it.next(), Some((1, None)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_into_slide_five() {
+        let v = vec![1, 2, 3, 4, 5];
+        let mut it = v.into_slide();
+
+        assert_eq!(it.next(), Some((1, Some(vec![2, 3, 4, 5]))));
+        assert_eq!(it.next(), Some((2, Some(vec![3, 4, 5]))));
+        assert_eq!(it.next(), Some((3, Some(vec![4, 5]))));
+        assert_eq!(it.next(), Some((4, Some(vec![5]))));
+        assert_eq!(it.next(), Some((5, None)));
+        assert_eq!(it.next(), None);
+    }
 }
\ No newline at end of file